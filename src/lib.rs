@@ -1,27 +1,72 @@
-use std::{fmt::Debug, hash::Hash, mem, vec::IntoIter};
+use std::{
+    collections::TryReserveError,
+    fmt::Debug,
+    hash::Hash,
+    io::{self, Write},
+    mem::{self, MaybeUninit},
+    ops::{Bound, Deref, DerefMut, RangeBounds},
+    ptr, slice,
+    vec::IntoIter,
+};
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "serde")]
+mod serde_support;
+
+/// Constructs a [`TinyVec`], building directly in the variant appropriate for the
+/// number of elements provided.
+///
+/// `tiny_vec![[T; N] => elem, elem, ...]` builds a `TinyVec<T, N>`. If the number of
+/// elements fits within `N` it is built on the stack, otherwise it is built straight
+/// on the heap, skipping the stack fill and immediate spill a literal overflowing
+/// `N` would otherwise cause. The capacity can also be left for inference, either
+/// empty (`tiny_vec![]`) or with a bare element list (`tiny_vec![1, 2, 3]`), in which
+/// case elements are always pushed one at a time.
+#[macro_export]
+macro_rules! tiny_vec {
+    () => {
+        $crate::TinyVec::new()
+    };
+    ([$t:ty; $cap:expr] => $($elem:expr),* $(,)?) => {
+        $crate::TinyVec::<$t, $cap>::from_elements([$($elem),*])
+    };
+    ($($elem:expr),+ $(,)?) => {
+        {
+            let mut tv = $crate::TinyVec::new();
+            $(tv.push($elem);)+
+            tv
+        }
+    };
+}
+
 /// A vector implementation that can store up to `STACK_CAPACITY` elements
 /// on the stack before moving its elements to the heap.
-#[derive(Clone)]
 pub struct TinyVec<T: Sized, const STACK_CAPACITY: usize> {
     inner: TinyVecInner<T, STACK_CAPACITY>,
     length: usize,
 }
 
-#[derive(Clone)]
 enum TinyVecInner<T: Sized, const STACK_CAPACITY: usize> {
-    Stack([Option<T>; STACK_CAPACITY]),
+    Stack([MaybeUninit<T>; STACK_CAPACITY]),
     Heap(Vec<T>),
 }
 
+/// Builds the `CapacityOverflow` variant of [`TryReserveError`] for callers that
+/// detect an overflowing capacity themselves (e.g. via `checked_add`) before ever
+/// reaching a `Vec` method that would report it. There is no public constructor for
+/// `TryReserveError`, so this forces one out of a `Vec` reserve that is guaranteed to
+/// report `CapacityOverflow`: requesting `usize::MAX` bytes always exceeds `isize::MAX`.
+fn capacity_overflow() -> TryReserveError {
+    Vec::<u8>::new().try_reserve_exact(usize::MAX).unwrap_err()
+}
+
 impl<T: Sized, const STACK_CAPACITY: usize> TinyVec<T, STACK_CAPACITY> {
     /// Creates a new empty [`TinyVec`].
     pub fn new() -> Self {
         Self {
-            inner: TinyVecInner::Stack([const { None }; STACK_CAPACITY]),
+            inner: TinyVecInner::Stack([const { MaybeUninit::uninit() }; STACK_CAPACITY]),
             length: 0,
         }
     }
@@ -50,7 +95,79 @@ impl<T: Sized, const STACK_CAPACITY: usize> TinyVec<T, STACK_CAPACITY> {
         };
 
         // move all items from the stack to the heap
-        heap.extend(array.into_iter().map(|elm| elm.unwrap()))
+        //
+        // SAFETY: we only spill once `self.length` has reached `STACK_CAPACITY`,
+        //         so every slot in `array` is guaranteed to be initialized.
+        heap.extend(array.into_iter().map(|elm| unsafe { elm.assume_init() }));
+    }
+
+    /// Fallible variant of [`spill`](Self::spill): moves the stack array onto the heap,
+    /// reserving room for `additional` more elements via [`Vec::try_reserve_exact`] so
+    /// that it spills even if the stack buffer is not yet full, and leaves `self`
+    /// untouched if the allocation fails.
+    fn try_spill(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        if matches!(self.inner, TinyVecInner::Heap(..)) {
+            return Ok(());
+        }
+
+        let length = self.length;
+
+        let Some(capacity) = length.checked_add(additional) else {
+            return Err(capacity_overflow());
+        };
+
+        let mut heap = Vec::new();
+        heap.try_reserve_exact(capacity)?;
+
+        let TinyVecInner::Stack(array) = mem::replace(&mut self.inner, TinyVecInner::Heap(heap))
+        else {
+            // NOTE: we returned early above if `inner` was already on the heap,
+            //       therefore we can safely assume this case is impossible.
+            unreachable!();
+        };
+
+        let TinyVecInner::Heap(heap) = &mut self.inner else {
+            // NOTE: we just spilled onto the heap, so `inner` cannot be of variant `Stack`,
+            //       therefore we can safely assume this case is impossible.
+            unreachable!();
+        };
+
+        // SAFETY: only the first `length` slots of `array` are initialized; the rest
+        //         are left alone, which is sound since `MaybeUninit`'s `Drop` never
+        //         runs the inner `T`'s destructor.
+        heap.extend(
+            array
+                .into_iter()
+                .take(length)
+                .map(|elm| unsafe { elm.assume_init() }),
+        );
+
+        Ok(())
+    }
+
+    /// Reserves capacity for at least `additional` more elements without aborting on
+    /// allocation failure. Fitting within `STACK_CAPACITY` never allocates; spilling or
+    /// growing past it reserves room for `additional` more on the heap, even if the
+    /// stack buffer is not yet full.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        if let TinyVecInner::Stack(_) = &self.inner {
+            let Some(total) = self.length.checked_add(additional) else {
+                return Err(capacity_overflow());
+            };
+
+            if total <= STACK_CAPACITY {
+                return Ok(());
+            }
+
+            return self.try_spill(additional);
+        }
+
+        let TinyVecInner::Heap(heap) = &mut self.inner else {
+            // NOTE: we just checked for `Stack` above, so `inner` must be `Heap`.
+            unreachable!();
+        };
+
+        heap.try_reserve(additional)
     }
 
     /// Pushes an element onto the [`TinyVec`] if we have reached the `STACK_CAPACITY` we spill onto the heap.
@@ -58,13 +175,27 @@ impl<T: Sized, const STACK_CAPACITY: usize> TinyVec<T, STACK_CAPACITY> {
         self.spill();
 
         match &mut self.inner {
-            TinyVecInner::Stack(stack) => stack[self.length] = Some(item),
+            TinyVecInner::Stack(stack) => {
+                stack[self.length].write(item);
+            }
             TinyVecInner::Heap(heap) => heap.push(item),
         }
 
         self.length += 1;
     }
 
+    /// Fallible variant of [`push`](Self::push): attempts to reserve room for the item
+    /// and, if the allocator fails, hands the item back instead of aborting.
+    pub fn try_push(&mut self, item: T) -> Result<(), T> {
+        if self.try_reserve(1).is_err() {
+            return Err(item);
+        }
+
+        self.push(item);
+
+        Ok(())
+    }
+
     /// Pops an element of of the [`TinyVec`], this however does not revert spillage.
     pub fn pop(&mut self) -> Option<T> {
         if self.length == 0 {
@@ -74,37 +205,50 @@ impl<T: Sized, const STACK_CAPACITY: usize> TinyVec<T, STACK_CAPACITY> {
         self.length -= 1;
 
         match &mut self.inner {
-            TinyVecInner::Stack(stack) => stack[self.length].take(),
+            // SAFETY: slot `self.length` was initialized by `push` and, since
+            //         the length was just decremented, has not been read since.
+            TinyVecInner::Stack(stack) => Some(unsafe { stack[self.length].assume_init_read() }),
             TinyVecInner::Heap(heap) => heap.pop(),
         }
     }
 
-    /// Gets the element at a given index if it exists.
-    pub fn get(&self, index: usize) -> Option<&T> {
-        if index >= self.length {
-            return None;
-        }
+    /// Returns whether or not the [`TinyVec`] has spilled onto the heap.
+    pub fn has_spilled(&self) -> bool {
+        matches!(self.inner, TinyVecInner::Heap(..))
+    }
 
-        match &self.inner {
-            TinyVecInner::Stack(stack) => stack[index].as_ref(),
-            TinyVecInner::Heap(heap) => heap.get(index),
-        }
+    /// Reclaims the stack buffer, reversing spillage: if the [`TinyVec`] has spilled
+    /// but its elements fit within `STACK_CAPACITY` again, moves them back onto the
+    /// stack and frees the heap allocation. `pop` alone does not do this, so a
+    /// [`TinyVec`] that briefly grew large would otherwise stay heap-allocated forever.
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_to(STACK_CAPACITY);
     }
 
-    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        if index >= self.length {
-            return None;
+    /// Like [`shrink_to_fit`](Self::shrink_to_fit), but only reclaims the stack buffer
+    /// if the elements also fit within `min_capacity`.
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        let min_capacity = min_capacity.min(STACK_CAPACITY);
+
+        if !self.has_spilled() || self.length > min_capacity {
+            return;
         }
 
-        match &mut self.inner {
-            TinyVecInner::Stack(stack) => stack[index].as_mut(),
-            TinyVecInner::Heap(heap) => heap.get_mut(index),
+        let TinyVecInner::Heap(heap) = &mut self.inner else {
+            // NOTE: we just checked `has_spilled`, so `inner` cannot be of variant `Stack`,
+            //       therefore we can safely assume this case is impossible.
+            unreachable!();
+        };
+
+        let mut stack = [const { MaybeUninit::uninit() }; STACK_CAPACITY];
+
+        // `heap` holds exactly `self.length` elements and `self.length <= STACK_CAPACITY`,
+        // so they all fit back onto the stack.
+        for (slot, elm) in stack.iter_mut().zip(heap.drain(..)) {
+            slot.write(elm);
         }
-    }
 
-    /// Returns whether or not the [`TinyVec`] has spilled onto the heap.
-    pub fn has_spilled(&self) -> bool {
-        matches!(self.inner, TinyVecInner::Heap(..))
+        self.inner = TinyVecInner::Stack(stack);
     }
 
     /// Returns `true` if the [`TinyVec`] contains no elements, otherwise `false`.
@@ -117,17 +261,370 @@ impl<T: Sized, const STACK_CAPACITY: usize> TinyVec<T, STACK_CAPACITY> {
         self.length
     }
 
-    /// Returns an [`Iterator`] over the items of the [`TinyVec`].
-    pub fn iter(&self) -> TinyVecIter<'_, T, STACK_CAPACITY> {
-        TinyVecIter { vec: self, idx: 0 }
-    }
-
     /// Extends the [`TinyVec`] by the elements of a given [`Iterator`].
     pub fn extend<I: Iterator<Item = T>>(&mut self, iter: I) {
         for elm in iter {
             self.push(elm);
         }
     }
+
+    /// Fallible variant of [`extend`](Self::extend): pushes elements one by one,
+    /// stopping and reporting the allocation failure as soon as one occurs.
+    pub fn try_extend<I: Iterator<Item = T>>(&mut self, iter: I) -> Result<(), TryReserveError> {
+        for elm in iter {
+            self.try_reserve(1)?;
+            self.push(elm);
+        }
+
+        Ok(())
+    }
+
+    /// Inserts `item` at `index`, shifting everything after it one slot to the right.
+    /// Spills onto the heap first if we're at `STACK_CAPACITY`, just like [`push`](Self::push).
+    pub fn insert(&mut self, index: usize, item: T) {
+        assert!(
+            index <= self.length,
+            "insertion index (is {index}) should be <= len (is {})",
+            self.length
+        );
+
+        self.spill();
+
+        match &mut self.inner {
+            TinyVecInner::Stack(stack) => unsafe {
+                // SAFETY: shifts the initialized `[index, length)` range right by one,
+                //         into the slot at `length`, which is uninitialized since
+                //         `length < STACK_CAPACITY` after `spill`.
+                let base = stack.as_mut_ptr().cast::<T>();
+                ptr::copy(base.add(index), base.add(index + 1), self.length - index);
+                base.add(index).write(item);
+            },
+            TinyVecInner::Heap(heap) => heap.insert(index, item),
+        }
+
+        self.length += 1;
+    }
+
+    /// Removes and returns the element at `index`, shifting everything after it one
+    /// slot to the left.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(
+            index < self.length,
+            "removal index (is {index}) should be < len (is {})",
+            self.length
+        );
+
+        self.length -= 1;
+
+        match &mut self.inner {
+            TinyVecInner::Stack(stack) => unsafe {
+                // SAFETY: slot `index` is initialized; after reading it out, the
+                //         `(index, length]` range is shifted left to close the gap.
+                let base = stack.as_mut_ptr().cast::<T>();
+                let removed = base.add(index).read();
+                ptr::copy(base.add(index + 1), base.add(index), self.length - index);
+                removed
+            },
+            TinyVecInner::Heap(heap) => heap.remove(index),
+        }
+    }
+
+    /// Removes and returns the element at `index` by moving the last element into
+    /// its place, instead of shifting everything after it like [`remove`](Self::remove).
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        assert!(
+            index < self.length,
+            "swap_remove index (is {index}) should be < len (is {})",
+            self.length
+        );
+
+        self.length -= 1;
+        let last = self.length;
+
+        match &mut self.inner {
+            TinyVecInner::Stack(stack) => {
+                stack.swap(index, last);
+
+                // SAFETY: `last` now holds the element originally at `index`, and
+                //         `last` is no longer within the initialized prefix.
+                unsafe { stack[last].assume_init_read() }
+            }
+            TinyVecInner::Heap(heap) => heap.swap_remove(index),
+        }
+    }
+
+    /// Shortens the [`TinyVec`] to `len`, dropping the excess elements. Does nothing
+    /// if `len` is greater than or equal to the current length.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.length {
+            return;
+        }
+
+        match &mut self.inner {
+            TinyVecInner::Stack(stack) => unsafe {
+                // SAFETY: slots `[len, self.length)` are initialized and about to
+                //         become unreachable, so drop them in place.
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                    stack.as_mut_ptr().add(len).cast::<T>(),
+                    self.length - len,
+                ));
+            },
+            TinyVecInner::Heap(heap) => heap.truncate(len),
+        }
+
+        self.length = len;
+    }
+
+    /// Retains only the elements for which `f` returns `true`, dropping the rest and
+    /// compacting the remainder in place.
+    ///
+    /// If `f` panics, the elements processed so far are left correctly compacted and
+    /// the rest are kept untouched (as if `f` had returned `true` for them) rather than
+    /// double-dropped, mirroring the guarantee [`Vec::retain`] makes.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        if let TinyVecInner::Heap(_) = &self.inner {
+            // `Vec::retain` already guards against a panicking `f` internally, but it
+            // only fixes up its own length; if `f` unwinds, the `self.length = heap.len()`
+            // that would normally follow never runs, leaving `self.length` stale. A drop
+            // guard resyncs it on every exit path, panic included.
+            struct SyncLenOnDrop<'a, T, const N: usize>(&'a mut TinyVec<T, N>);
+
+            impl<T, const N: usize> Drop for SyncLenOnDrop<'_, T, N> {
+                fn drop(&mut self) {
+                    let TinyVecInner::Heap(heap) = &self.0.inner else {
+                        // NOTE: we only construct this guard while `inner` is `Heap`,
+                        //       and `retain` never changes variants.
+                        unreachable!();
+                    };
+
+                    self.0.length = heap.len();
+                }
+            }
+
+            let guard = SyncLenOnDrop(self);
+
+            let TinyVecInner::Heap(heap) = &mut guard.0.inner else {
+                unreachable!();
+            };
+
+            heap.retain(f);
+
+            return;
+        }
+
+        // Hide every element from `self.length` (and thus from `Drop for TinyVec`) for
+        // the duration of the loop, the same way `drain` does, so a panic out of `f`
+        // can't expose or double-drop a slot whose fate hasn't been finalized yet.
+        let original_len = self.length;
+        self.length = 0;
+
+        struct BackshiftOnDrop<'a, T, const N: usize> {
+            tv: &'a mut TinyVec<T, N>,
+            processed_len: usize,
+            deleted_cnt: usize,
+            original_len: usize,
+        }
+
+        impl<T, const N: usize> Drop for BackshiftOnDrop<'_, T, N> {
+            fn drop(&mut self) {
+                if self.deleted_cnt > 0 {
+                    let base = self.tv.storage_mut_ptr();
+
+                    // SAFETY: `[processed_len, original_len)` are still initialized,
+                    //         untouched elements (nothing past `processed_len` has been
+                    //         moved or dropped), so shifting them down by `deleted_cnt`
+                    //         closes the gaps left behind without aliasing or UB.
+                    unsafe {
+                        ptr::copy(
+                            base.add(self.processed_len),
+                            base.add(self.processed_len - self.deleted_cnt),
+                            self.original_len - self.processed_len,
+                        );
+                    }
+                }
+
+                self.tv.length = self.original_len - self.deleted_cnt;
+            }
+        }
+
+        let mut g = BackshiftOnDrop {
+            tv: self,
+            processed_len: 0,
+            deleted_cnt: 0,
+            original_len,
+        };
+
+        while g.processed_len != g.original_len {
+            let cur = unsafe { g.tv.storage_mut_ptr().add(g.processed_len) };
+
+            // SAFETY: slot `processed_len` lies within `[0, original_len)`, which is
+            //         still fully initialized and has not been moved or dropped yet.
+            let keep = f(unsafe { &*cur });
+
+            if !keep {
+                g.processed_len += 1;
+                g.deleted_cnt += 1;
+
+                // SAFETY: see above.
+                unsafe {
+                    ptr::drop_in_place(cur);
+                }
+
+                continue;
+            }
+
+            if g.deleted_cnt > 0 {
+                let hole = unsafe { g.tv.storage_mut_ptr().add(g.processed_len - g.deleted_cnt) };
+
+                // SAFETY: `hole` lies strictly before `cur` and was already vacated by
+                //         an earlier deletion, so this moves the surviving element down
+                //         without aliasing `cur`.
+                unsafe {
+                    ptr::copy_nonoverlapping(cur, hole, 1);
+                }
+            }
+
+            g.processed_len += 1;
+        }
+
+        drop(g);
+    }
+
+    /// Removes the elements in `range`, returning a [`Drain`] over them. The
+    /// untouched tail is shifted down to close the gap once the `Drain` is dropped,
+    /// even if it was dropped before being fully consumed. If the `Drain` is leaked
+    /// instead, the [`TinyVec`] is left truncated to the start of the drained range.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, STACK_CAPACITY> {
+        let len = self.length;
+
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n + 1,
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => len,
+        };
+
+        assert!(
+            start <= end,
+            "drain start (is {start}) should be <= end (is {end})"
+        );
+        assert!(
+            end <= len,
+            "drain end (is {end}) should be <= len (is {len})"
+        );
+
+        // Hide the drained range, and the tail after it, from `self.length` for the
+        // duration of the drain, so a leaked `Drain` simply truncates instead of
+        // exposing or double-dropping anything.
+        self.length = start;
+
+        if let TinyVecInner::Heap(heap) = &mut self.inner {
+            // SAFETY: elements `[0, start)` are still initialized and owned by `heap`;
+            //         the rest are now owned by the `Drain`.
+            unsafe {
+                heap.set_len(start);
+            }
+        }
+
+        Drain {
+            vec: self,
+            start,
+            idx: start,
+            end,
+            orig_len: len,
+        }
+    }
+
+    /// Returns a pointer to the start of the backing storage, independent of how much
+    /// of it `self.length` currently considers initialized. Used by [`Drain`] to reach
+    /// elements temporarily hidden from `self.length` while it is live.
+    fn storage_mut_ptr(&mut self) -> *mut T {
+        match &mut self.inner {
+            TinyVecInner::Stack(stack) => stack.as_mut_ptr().cast::<T>(),
+            TinyVecInner::Heap(heap) => heap.as_mut_ptr(),
+        }
+    }
+
+    /// Builds a [`TinyVec`] from a fixed-size array of elements, constructed directly
+    /// in the variant appropriate for `INVOKED_ELEM_COUNT`. Used by the [`tiny_vec!`]
+    /// macro so that a literal exceeding `STACK_CAPACITY` goes straight to the heap
+    /// instead of filling the stack buffer and immediately spilling it.
+    #[doc(hidden)]
+    pub fn from_elements<const INVOKED_ELEM_COUNT: usize>(
+        elements: [T; INVOKED_ELEM_COUNT],
+    ) -> Self {
+        if INVOKED_ELEM_COUNT <= STACK_CAPACITY {
+            let mut tv = Self::new();
+            tv.extend(elements.into_iter());
+            tv
+        } else {
+            Self {
+                inner: TinyVecInner::Heap(Vec::from(elements)),
+                length: INVOKED_ELEM_COUNT,
+            }
+        }
+    }
+}
+
+impl<T: Sized + Clone, const N: usize> TinyVec<T, N> {
+    /// Extends the [`TinyVec`] by cloning every element of `slice` onto the end,
+    /// spilling onto the heap as needed through the normal [`push`](Self::push) path.
+    pub fn extend_from_slice(&mut self, slice: &[T]) {
+        self.extend(slice.iter().cloned());
+    }
+}
+
+impl<T: Sized, const N: usize> Deref for TinyVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        match &self.inner {
+            // SAFETY: only the first `self.length` slots of `stack` are initialized.
+            TinyVecInner::Stack(stack) => unsafe {
+                slice::from_raw_parts(stack.as_ptr().cast::<T>(), self.length)
+            },
+            TinyVecInner::Heap(heap) => heap.as_slice(),
+        }
+    }
+}
+
+impl<T: Sized, const N: usize> DerefMut for TinyVec<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match &mut self.inner {
+            // SAFETY: only the first `self.length` slots of `stack` are initialized.
+            TinyVecInner::Stack(stack) => unsafe {
+                slice::from_raw_parts_mut(stack.as_mut_ptr().cast::<T>(), self.length)
+            },
+            TinyVecInner::Heap(heap) => heap.as_mut_slice(),
+        }
+    }
+}
+
+impl<T: Sized, const N: usize> Drop for TinyVec<T, N> {
+    fn drop(&mut self) {
+        if let TinyVecInner::Stack(stack) = &mut self.inner {
+            // SAFETY: only the first `self.length` slots of `stack` are initialized,
+            //         and we are being dropped so nothing will observe them again.
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                    stack.as_mut_ptr().cast::<T>(),
+                    self.length,
+                ));
+            }
+        }
+    }
+}
+
+impl<T: Sized + Clone, const N: usize> Clone for TinyVec<T, N> {
+    fn clone(&self) -> Self {
+        let mut cloned = Self::new();
+        cloned.extend(self.iter().cloned());
+        cloned
+    }
 }
 
 impl<T: Sized, const N: usize> Default for TinyVec<T, N> {
@@ -146,25 +643,32 @@ impl<T: Sized + PartialEq, const A: usize, const B: usize> PartialEq<TinyVec<T,
     for TinyVec<T, B>
 {
     fn eq(&self, other: &TinyVec<T, A>) -> bool {
-        // check lengths
-        if self.length != other.length {
-            return false;
-        }
-
-        // check each element for equality
-        for (a, b) in self.iter().zip(other.iter()) {
-            if a.ne(b) {
-                return false;
-            }
-        }
-
-        // both vecs are equal at this point
-        true
+        self.deref() == other.deref()
     }
 }
 
 impl<T: Sized + Eq, const N: usize> Eq for TinyVec<T, N> {}
 
+/// Lets a [`TinyVec<u8, N>`](TinyVec) serve as a small reusable serialization scratch
+/// buffer: short writes stay on the stack and only spill once they exceed `N` bytes.
+/// Reading back out is handled by the [`Deref<Target=[u8]>`](Deref) impl, whose slice
+/// already implements [`Read`](std::io::Read) (e.g. `io::Read::read(&mut &tv[..], buf)`).
+impl<const N: usize> Write for TinyVec<u8, N> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.extend_from_slice(buf);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 impl<T: Sized, const N: usize, I: Iterator<Item = T>> From<I> for TinyVec<T, N> {
     fn from(value: I) -> Self {
         let mut tv = Self::new();
@@ -175,9 +679,7 @@ impl<T: Sized, const N: usize, I: Iterator<Item = T>> From<I> for TinyVec<T, N>
 
 impl<T: Sized + Hash, const N: usize> Hash for TinyVec<T, N> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        for elm in self.iter().enumerate() {
-            elm.hash(state);
-        }
+        self.deref().hash(state);
     }
 }
 
@@ -186,12 +688,23 @@ impl<T: Sized, const N: usize> IntoIterator for TinyVec<T, N> {
     type IntoIter = TinyVecIntoIter<T, N>;
 
     fn into_iter(self) -> Self::IntoIter {
+        let length = self.length;
+
+        // NOTE: `TinyVec` has a `Drop` impl that would drop the initialized
+        //       prefix of `inner` out from under us, so we move `inner` out
+        //       through a `ManuallyDrop` wrapper instead of destructuring `self`.
+        let this = mem::ManuallyDrop::new(self);
+
+        // SAFETY: `this` is never used again, so `inner` is read exactly once.
+        let inner = unsafe { ptr::read(&this.inner) };
+
         TinyVecIntoIter {
-            inner: match self.inner {
+            inner: match inner {
                 TinyVecInner::Stack(stack) => TinyVecIntoIterInner::Stack(stack),
                 TinyVecInner::Heap(heap) => TinyVecIntoIterInner::Heap(heap.into_iter()),
             },
             idx: 0,
+            len: length,
         }
     }
 }
@@ -199,10 +712,11 @@ impl<T: Sized, const N: usize> IntoIterator for TinyVec<T, N> {
 pub struct TinyVecIntoIter<T: Sized, const STACK_CAPACITY: usize> {
     inner: TinyVecIntoIterInner<T, STACK_CAPACITY>,
     idx: usize,
+    len: usize,
 }
 
 enum TinyVecIntoIterInner<T: Sized, const STACK_CAPACITY: usize> {
-    Stack([Option<T>; STACK_CAPACITY]),
+    Stack([MaybeUninit<T>; STACK_CAPACITY]),
     Heap(IntoIter<T>),
 }
 
@@ -210,46 +724,100 @@ impl<T: Sized, const STACK_CAPACITY: usize> Iterator for TinyVecIntoIter<T, STAC
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let idx = self.idx;
-        self.idx += 1;
-
         match &mut self.inner {
             TinyVecIntoIterInner::Stack(stack) => {
-                if idx >= stack.len() {
+                if self.idx >= self.len {
                     return None;
                 }
 
-                stack[idx].take()
+                let idx = self.idx;
+                self.idx += 1;
+
+                // SAFETY: slot `idx` lies within the initialized prefix (`0..self.len`)
+                //         and has not been read yet, since `idx` only ever increases.
+                Some(unsafe { stack[idx].assume_init_read() })
             }
             TinyVecIntoIterInner::Heap(heap) => heap.next(),
         }
     }
 }
 
-pub struct TinyVecIter<'a, T: Sized, const STACK_CAPACITY: usize> {
-    vec: &'a TinyVec<T, STACK_CAPACITY>,
+impl<T: Sized, const STACK_CAPACITY: usize> Drop for TinyVecIntoIter<T, STACK_CAPACITY> {
+    fn drop(&mut self) {
+        if let TinyVecIntoIterInner::Stack(stack) = &mut self.inner {
+            // SAFETY: slots in `self.idx..self.len` have not been yielded yet,
+            //         so they are still initialized and have not been dropped.
+            for slot in &mut stack[self.idx..self.len] {
+                unsafe {
+                    slot.assume_init_drop();
+                }
+            }
+        }
+    }
+}
+
+/// An iterator that removes a range of elements from a [`TinyVec`] and yields them.
+/// Created by [`TinyVec::drain`].
+///
+/// Dropping the `Drain` closes the gap it left behind by shifting the untouched tail
+/// down, regardless of whether the `Drain` was fully consumed first; if it is leaked
+/// instead of dropped, the [`TinyVec`] is simply left truncated to the start of the
+/// drained range.
+pub struct Drain<'a, T: Sized, const STACK_CAPACITY: usize> {
+    vec: &'a mut TinyVec<T, STACK_CAPACITY>,
+    start: usize,
     idx: usize,
+    end: usize,
+    orig_len: usize,
 }
 
-impl<'a, T: Sized, const STACK_CAPACITY: usize> Iterator for TinyVecIter<'a, T, STACK_CAPACITY> {
-    type Item = &'a T;
+impl<'a, T: Sized, const STACK_CAPACITY: usize> Iterator for Drain<'a, T, STACK_CAPACITY> {
+    type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        // get the current index and increment
+        if self.idx >= self.end {
+            return None;
+        }
+
         let idx = self.idx;
         self.idx += 1;
 
-        // get the element from the vec
-        match &self.vec.inner {
-            TinyVecInner::Stack(stack) => {
-                // return `None` if we are out of bounds
-                if self.idx >= self.vec.length {
-                    return None;
-                }
+        // SAFETY: slot `idx` lies in `[start, end)`, which `self.vec.length` no longer
+        //         covers while the `Drain` is alive, so nothing else can observe or
+        //         drop it out from under us.
+        Some(unsafe { self.vec.storage_mut_ptr().add(idx).read() })
+    }
+}
+
+impl<'a, T: Sized, const STACK_CAPACITY: usize> Drop for Drain<'a, T, STACK_CAPACITY> {
+    fn drop(&mut self) {
+        // drop whatever the caller did not consume before dropping the `Drain`
+        for idx in self.idx..self.end {
+            // SAFETY: elements `[idx, end)` have not been yielded yet.
+            unsafe {
+                ptr::drop_in_place(self.vec.storage_mut_ptr().add(idx));
+            }
+        }
+
+        let tail_len = self.orig_len - self.end;
+        let new_len = self.start + tail_len;
+
+        if tail_len > 0 {
+            // SAFETY: `[end, orig_len)` are still initialized and need to move down
+            //         to close the gap left by the drained range.
+            unsafe {
+                let base = self.vec.storage_mut_ptr();
+                ptr::copy(base.add(self.end), base.add(self.start), tail_len);
+            }
+        }
+
+        self.vec.length = new_len;
 
-                stack[idx].as_ref()
+        if let TinyVecInner::Heap(heap) = &mut self.vec.inner {
+            // SAFETY: elements `[0, new_len)` are initialized and owned by `heap` again.
+            unsafe {
+                heap.set_len(new_len);
             }
-            TinyVecInner::Heap(heap) => heap.get(idx),
         }
     }
 }
@@ -0,0 +1,75 @@
+//! `serde` (de)serialization support, gated behind the `serde` feature.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+use crate::{TinyVec, TinyVecInner};
+
+/// Upper bound on how many elements we'll eagerly preallocate based on a
+/// deserializer-reported `size_hint`. A `size_hint` is attacker-influenced for
+/// untrusted input, so a lying hint must not be able to force an unbounded
+/// allocation before a single element has actually been read.
+const MAX_PREALLOCATED_ELEMENTS: usize = 4096;
+
+impl<T: Serialize, const N: usize> Serialize for TinyVec<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+
+        for elm in self.iter() {
+            seq.serialize_element(elm)?;
+        }
+
+        seq.end()
+    }
+}
+
+struct TinyVecVisitor<T, const N: usize> {
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for TinyVecVisitor<T, N> {
+    type Value = TinyVec<T, N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a sequence of at most {N} elements before spilling onto the heap"
+        )
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut tv = TinyVec::new();
+
+        // If the deserializer can tell us up front that the sequence won't fit on the
+        // stack, allocate the heap `Vec` with the right capacity once instead of letting
+        // `push` grow it element by element. The hint is clamped first since it comes
+        // from the (possibly untrusted) input and must not force an unbounded
+        // allocation before we've actually read that many elements.
+        if let Some(hint) = seq.size_hint() {
+            let capacity = hint.min(N.max(MAX_PREALLOCATED_ELEMENTS));
+
+            if capacity > N {
+                tv.inner = TinyVecInner::Heap(Vec::with_capacity(capacity));
+            }
+        }
+
+        // Pushing one element at a time, rather than collecting into a `Vec` and
+        // wrapping it, keeps short sequences on the stack the way `TinyVec` intends.
+        while let Some(elm) = seq.next_element()? {
+            tv.push(elm);
+        }
+
+        Ok(tv)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for TinyVec<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(TinyVecVisitor {
+            marker: PhantomData,
+        })
+    }
+}
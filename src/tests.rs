@@ -1,4 +1,6 @@
-use crate::TinyVec;
+use std::io::Write;
+
+use crate::{tiny_vec, TinyVec};
 
 #[test]
 fn test_push_and_pop() {
@@ -70,10 +72,10 @@ fn test_get() {
     let mut tv = TinyVec::<_, 4>::new();
 
     tv.push(12);
-    assert_eq!(tv.get(0), Some(&12));
+    assert_eq!(tv.first(), Some(&12));
 
-    *(tv.get_mut(0).unwrap()) = 55;
-    assert_eq!(tv.get(0), Some(&55));
+    *(tv.first_mut().unwrap()) = 55;
+    assert_eq!(tv.first(), Some(&55));
 }
 
 #[test]
@@ -104,10 +106,244 @@ fn test_into_iter() {
     for (idx, elm) in tv.into_iter().enumerate() {
         assert_eq!(idx, elm);
     }
-    
+
     let tv = TinyVec::<_, 8>::from(0..12);
 
     for (idx, elm) in tv.into_iter().enumerate() {
         assert_eq!(idx, elm);
     }
 }
+
+#[test]
+fn test_tiny_vec_macro() {
+    let empty: TinyVec<i32, 4> = tiny_vec![];
+    assert!(empty.is_empty());
+
+    let inferred: TinyVec<_, 4> = tiny_vec![1, 2, 3];
+    assert_eq!(inferred.len(), 3);
+    assert!(!inferred.has_spilled());
+
+    let fits = tiny_vec![[i32; 4] => 1, 2, 3];
+    assert_eq!(&*fits, &[1, 2, 3]);
+    assert!(!fits.has_spilled());
+
+    let overflows = tiny_vec![[i32; 4] => 1, 2, 3, 4, 5, 6];
+    assert_eq!(&*overflows, &[1, 2, 3, 4, 5, 6]);
+    assert!(overflows.has_spilled());
+}
+
+#[test]
+fn test_write() {
+    let mut tv = TinyVec::<u8, 4>::new();
+
+    write!(tv, "ab").unwrap();
+    assert!(!tv.has_spilled());
+    assert_eq!(&*tv, b"ab");
+
+    write!(tv, "cdef").unwrap();
+    assert!(tv.has_spilled());
+    assert_eq!(&*tv, b"abcdef");
+}
+
+#[test]
+fn test_try_push_and_try_extend() {
+    let mut tv = TinyVec::<_, 2>::new();
+
+    assert_eq!(tv.try_push(1), Ok(()));
+    assert_eq!(tv.try_push(2), Ok(()));
+    assert!(!tv.has_spilled());
+
+    assert_eq!(tv.try_push(3), Ok(()));
+    assert!(tv.has_spilled());
+    assert_eq!(&*tv, &[1, 2, 3]);
+
+    tv.try_extend(4..7).unwrap();
+    assert_eq!(&*tv, &[1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn test_try_reserve_spills_past_capacity() {
+    let mut tv = TinyVec::<_, 4>::new();
+    tv.extend(0..2);
+    assert!(!tv.has_spilled());
+
+    // `length + additional` overflows `STACK_CAPACITY` even though `length` alone
+    // does not, so this must spill rather than silently no-op.
+    assert_eq!(tv.try_reserve(10), Ok(()));
+    assert!(tv.has_spilled());
+    assert_eq!(&*tv, &[0, 1]);
+}
+
+#[test]
+fn test_try_reserve_reports_capacity_overflow() {
+    let mut tv = TinyVec::<u8, 4>::new();
+    tv.extend(0..2);
+
+    // `length + additional` would overflow `usize`; this must report a
+    // `CapacityOverflow` error rather than panicking (debug) or wrapping (release).
+    assert!(tv.try_reserve(usize::MAX - 1).is_err());
+    assert!(!tv.has_spilled());
+    assert_eq!(&*tv, &[0, 1]);
+}
+
+#[test]
+fn test_shrink_to_fit() {
+    let mut tv = TinyVec::<_, 4>::new();
+    tv.extend(0..6);
+    assert!(tv.has_spilled());
+
+    tv.pop();
+    tv.pop();
+    assert!(tv.has_spilled());
+
+    tv.shrink_to_fit();
+    assert!(!tv.has_spilled());
+    assert_eq!(&*tv, &[0, 1, 2, 3]);
+}
+
+#[test]
+fn test_shrink_to() {
+    let mut tv = TinyVec::<_, 4>::new();
+    tv.extend(0..6);
+
+    tv.pop();
+    tv.pop();
+    tv.shrink_to(1);
+    assert!(tv.has_spilled());
+
+    tv.shrink_to(4);
+    assert!(!tv.has_spilled());
+    assert_eq!(&*tv, &[0, 1, 2, 3]);
+}
+
+#[test]
+fn test_insert_and_remove() {
+    let mut tv = TinyVec::<_, 4>::new();
+    tv.extend([1, 2, 4].into_iter());
+
+    tv.insert(2, 3);
+    assert_eq!(&*tv, &[1, 2, 3, 4]);
+    assert!(!tv.has_spilled());
+
+    tv.insert(4, 5);
+    assert!(tv.has_spilled());
+    assert_eq!(&*tv, &[1, 2, 3, 4, 5]);
+
+    assert_eq!(tv.remove(0), 1);
+    assert_eq!(&*tv, &[2, 3, 4, 5]);
+}
+
+#[test]
+fn test_swap_remove() {
+    let mut tv = TinyVec::<_, 4>::new();
+    tv.extend([1, 2, 3, 4].into_iter());
+
+    assert_eq!(tv.swap_remove(0), 1);
+    assert_eq!(&*tv, &[4, 2, 3]);
+}
+
+#[test]
+fn test_truncate() {
+    let mut tv = TinyVec::<_, 4>::new();
+    tv.extend(0..8);
+    assert!(tv.has_spilled());
+
+    tv.truncate(3);
+    assert_eq!(&*tv, &[0, 1, 2]);
+
+    tv.truncate(10);
+    assert_eq!(&*tv, &[0, 1, 2]);
+}
+
+#[test]
+fn test_retain() {
+    let mut tv = TinyVec::<_, 4>::new();
+    tv.extend(0..3);
+    tv.retain(|&elm| elm % 2 == 0);
+    assert_eq!(&*tv, &[0, 2]);
+
+    let mut tv = TinyVec::<_, 4>::new();
+    tv.extend(0..8);
+    tv.retain(|&elm| elm % 2 == 0);
+    assert_eq!(&*tv, &[0, 2, 4, 6]);
+}
+
+#[test]
+fn test_retain_panic_safety() {
+    use std::panic::{self, AssertUnwindSafe};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct DropCounter(u32, &'static AtomicUsize);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.1.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+    DROPS.store(0, Ordering::SeqCst);
+
+    let mut tv = TinyVec::<_, 4>::new();
+    tv.extend((0..4).map(|id| DropCounter(id, &DROPS)));
+
+    // Drops id `0`, then panics while examining id `1`: under the old implementation
+    // this duplicated id `0`'s bytes across both its old and new slots, and the
+    // stale `self.length` from the unwind would drop that duplicate a second time.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        tv.retain(|elm| {
+            if elm.0 == 1 {
+                panic!("boom");
+            }
+            elm.0 != 0
+        });
+    }));
+    assert!(result.is_err());
+    assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+
+    drop(tv);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn test_drain() {
+    let mut tv = TinyVec::<_, 4>::new();
+    tv.extend(0..3);
+
+    let drained: Vec<_> = tv.drain(1..).collect();
+    assert_eq!(drained, vec![1, 2]);
+    assert_eq!(&*tv, &[0]);
+
+    let mut tv = TinyVec::<_, 4>::new();
+    tv.extend(0..8);
+
+    // drop the `Drain` without fully consuming it
+    {
+        let mut drain = tv.drain(1..5);
+        assert_eq!(drain.next(), Some(1));
+    }
+
+    assert_eq!(&*tv, &[0, 5, 6, 7]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_round_trip() {
+    let mut tv: TinyVec<i32, 4> = TinyVec::new();
+    tv.extend(0..3);
+
+    let json = serde_json::to_string(&tv).unwrap();
+    assert_eq!(json, "[0,1,2]");
+
+    let back: TinyVec<i32, 4> = serde_json::from_str(&json).unwrap();
+    assert!(!back.has_spilled());
+    assert_eq!(back, tv);
+
+    let mut spilled: TinyVec<i32, 4> = TinyVec::new();
+    spilled.extend(0..10);
+
+    let json = serde_json::to_string(&spilled).unwrap();
+    let back: TinyVec<i32, 4> = serde_json::from_str(&json).unwrap();
+    assert!(back.has_spilled());
+    assert_eq!(back, spilled);
+}